@@ -1,10 +1,95 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+use nalgebra::{Matrix3, Rotation3, UnitQuaternion, Vector3};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use std::collections::HashMap;
 use std::fs;
 
+// Rocket League stores Pitch/Yaw/Roll as signed Unreal rotation units,
+// where a full 360 degree turn is 65536 UU.
+const UU_PER_DEGREE: f64 = 182.04;
+
+// Capture rate assumed when a command's `fps` argument is omitted.
+const DEFAULT_FPS: f64 = 30.0;
+
+fn uu_to_rad(uu: i32) -> f64 {
+    (uu as f64 / UU_PER_DEGREE).to_radians()
+}
+
+fn rad_to_uu(rad: f64) -> i32 {
+    (rad.to_degrees() * UU_PER_DEGREE).round() as i32
+}
+
+fn rotation_to_quat(rotation: &Rotation) -> UnitQuaternion<f64> {
+    let pitch = uu_to_rad(rotation.pitch);
+    let yaw = uu_to_rad(rotation.yaw);
+    let roll = uu_to_rad(rotation.roll);
+    // nalgebra's from_euler_angles rotates +X up in Z for *negative* pitch, while
+    // this crate's Pitch (and the atan2(dz, horizontal_len) used to derive it in
+    // generate_orbit/import_camera_events) is positive-up. Negate here, and again
+    // when decomposing back in quat_to_rotation, so forward vectors computed via
+    // the quaternion match the atan2-based ones everywhere else in the file.
+    UnitQuaternion::from_euler_angles(roll, -pitch, yaw)
+}
+
+fn quat_to_rotation(quat: &UnitQuaternion<f64>) -> Rotation {
+    let (roll, pitch, yaw) = quat.euler_angles();
+    Rotation {
+        pitch: rad_to_uu(-pitch),
+        roll: rad_to_uu(roll),
+        yaw: rad_to_uu(yaw),
+    }
+}
+
+// Averages a window of quaternions, flipping any that have drifted into the
+// opposite hemisphere (q and -q represent the same rotation) relative to the
+// running reference so they don't cancel out, then renormalizes the sum. The
+// reference is the accumulated sum so far rather than a fixed first sample,
+// so it tracks a window that sweeps through a large cumulative rotation
+// instead of comparing later samples against a stale starting orientation.
+fn average_quaternions(quats: &[UnitQuaternion<f64>]) -> UnitQuaternion<f64> {
+    let mut sum = quats[0].quaternion().coords;
+
+    for q in &quats[1..] {
+        let coords = q.quaternion().coords;
+        if coords.dot(&sum) < 0.0 {
+            sum -= coords;
+        } else {
+            sum += coords;
+        }
+    }
+
+    UnitQuaternion::from_quaternion(nalgebra::Quaternion::from(sum))
+}
+
+fn reflect_across_axis(v: Vector3<f64>, axis: &str) -> Vector3<f64> {
+    match axis {
+        "x" => Vector3::new(-v.x, v.y, v.z),
+        "y" => Vector3::new(v.x, -v.y, v.z),
+        "z" => Vector3::new(v.x, v.y, -v.z),
+        _ => v,
+    }
+}
+
+// Reflects a rotation across the plane perpendicular to `axis` by reflecting
+// the camera's forward and up basis vectors and rebuilding an orthonormal
+// frame from them, rather than sign-flipping individual Euler angles (which
+// produces the wrong orientation for anything but axis-aligned rotations).
+fn reflect_rotation(rotation: &Rotation, axis: &str) -> Rotation {
+    let quat = rotation_to_quat(rotation);
+    let forward = reflect_across_axis(quat * Vector3::x(), axis);
+    let up = reflect_across_axis(quat * Vector3::z(), axis);
+
+    let forward = forward.normalize();
+    let right = up.cross(&forward).normalize();
+    let up = forward.cross(&right);
+
+    let basis = Matrix3::from_columns(&[forward, right, up]);
+    let rotation_matrix = Rotation3::from_matrix_unchecked(basis);
+    quat_to_rotation(&UnitQuaternion::from_rotation_matrix(&rotation_matrix))
+}
+
 // Camera data structures
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct Position {
@@ -45,6 +130,23 @@ struct CameraKeyframe {
 // Type alias for camera data
 type CameraData = HashMap<String, CameraKeyframe>;
 
+// Portable position+target camera event, as used by external viewer/model
+// converter tooling instead of this crate's Rocket League JSON layout.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Vec3Event {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CameraEvent {
+    position: Vec3Event,
+    target: Vec3Event,
+    fov: f64,
+    time: f64,
+}
+
 // ========================================
 // FILE OPERATIONS
 // ========================================
@@ -149,9 +251,9 @@ fn transform_rotation_offset(data: String, pitch: i32, yaw: i32, roll: i32, use_
     
     // Convert degrees to Unreal units if needed (1 degree = 182.04 UU)
     let (p, y, r) = if use_degrees {
-        ((pitch as f64 * 182.04) as i32,
-         (yaw as f64 * 182.04) as i32,
-         (roll as f64 * 182.04) as i32)
+        ((pitch as f64 * UU_PER_DEGREE) as i32,
+         (yaw as f64 * UU_PER_DEGREE) as i32,
+         (roll as f64 * UU_PER_DEGREE) as i32)
     } else {
         (pitch, yaw, roll)
     };
@@ -171,14 +273,7 @@ fn transform_rotation_offset(data: String, pitch: i32, yaw: i32, roll: i32, use_
 // ========================================
 
 #[tauri::command]
-fn transform_mirror(
-    data: String,
-    axis: String,
-    flip_pitch: bool,
-    flip_yaw: bool,
-    flip_roll: bool,
-    bounded: bool
-) -> Result<String, String> {
+fn transform_mirror(data: String, axis: String, bounded: bool) -> Result<String, String> {
     let mut camera_data: CameraData = serde_json::from_str(&data)
         .map_err(|e| format!("Failed to parse JSON: {}", e))?;
     
@@ -235,17 +330,9 @@ fn transform_mirror(
             }
             _ => return Err(format!("Invalid axis: {}", axis)),
         }
-        
-        // Flip rotations if requested
-        if flip_pitch {
-            keyframe.rotation.pitch = -keyframe.rotation.pitch;
-        }
-        if flip_yaw {
-            keyframe.rotation.yaw = -keyframe.rotation.yaw;
-        }
-        if flip_roll {
-            keyframe.rotation.roll = -keyframe.rotation.roll;
-        }
+
+        // Reflect orientation geometrically rather than sign-flipping angles
+        keyframe.rotation = reflect_rotation(&keyframe.rotation, axis.as_str());
     }
     
     serde_json::to_string_pretty(&camera_data)
@@ -257,29 +344,31 @@ fn transform_mirror(
 // ========================================
 
 #[tauri::command]
-fn transform_speed(data: String, multiplier: f64) -> Result<String, String> {
+fn transform_speed(data: String, multiplier: f64, fps: Option<f64>) -> Result<String, String> {
     let mut camera_data: CameraData = serde_json::from_str(&data)
         .map_err(|e| format!("Failed to parse JSON: {}", e))?;
-    
+    let fps = fps.unwrap_or(DEFAULT_FPS);
+
     for (_, keyframe) in camera_data.iter_mut() {
         keyframe.timestamp /= multiplier;
-        keyframe.frame = (keyframe.timestamp * 30.0).round() as i32; // Maintain 30 FPS sync
+        keyframe.frame = (keyframe.timestamp * fps).round() as i32;
     }
-    
+
     serde_json::to_string_pretty(&camera_data)
         .map_err(|e| format!("Failed to serialize: {}", e))
 }
 
 #[tauri::command]
-fn transform_time_offset(data: String, offset_seconds: f64) -> Result<String, String> {
+fn transform_time_offset(data: String, offset_seconds: f64, fps: Option<f64>) -> Result<String, String> {
     let mut camera_data: CameraData = serde_json::from_str(&data)
         .map_err(|e| format!("Failed to parse JSON: {}", e))?;
-    
+    let fps = fps.unwrap_or(DEFAULT_FPS);
+
     for (_, keyframe) in camera_data.iter_mut() {
         keyframe.timestamp += offset_seconds;
-        keyframe.frame = (keyframe.timestamp * 30.0).round() as i32; // Maintain 30 FPS sync
+        keyframe.frame = (keyframe.timestamp * fps).round() as i32;
     }
-    
+
     serde_json::to_string_pretty(&camera_data)
         .map_err(|e| format!("Failed to serialize: {}", e))
 }
@@ -289,54 +378,72 @@ fn transform_time_offset(data: String, offset_seconds: f64) -> Result<String, St
 // ========================================
 
 #[tauri::command]
-fn reverse_path(data: String) -> Result<String, String> {
+fn reverse_path(data: String, fps: Option<f64>) -> Result<String, String> {
     let camera_data: CameraData = serde_json::from_str(&data)
         .map_err(|e| format!("Failed to parse JSON: {}", e))?;
-    
+    let fps = fps.unwrap_or(DEFAULT_FPS);
+
     let mut timestamps: Vec<f64> = camera_data.values().map(|kf| kf.timestamp).collect();
     timestamps.sort_by(|a, b| a.partial_cmp(b).unwrap());
-    
+
     let max_time = timestamps.last().copied().unwrap_or(0.0);
     let min_time = timestamps.first().copied().unwrap_or(0.0);
-    
+
     let mut reversed: CameraData = HashMap::new();
-    
+
     for (key, mut keyframe) in camera_data {
         keyframe.timestamp = max_time - keyframe.timestamp + min_time;
-        keyframe.frame = (keyframe.timestamp * 30.0).round() as i32;
+        keyframe.frame = (keyframe.timestamp * fps).round() as i32;
         reversed.insert(key, keyframe);
     }
-    
+
     serde_json::to_string_pretty(&reversed)
         .map_err(|e| format!("Failed to serialize: {}", e))
 }
 
 #[tauri::command]
-fn smooth_path(data: String, window_size: usize) -> Result<String, String> {
+fn reframe_path(data: String, old_fps: f64, new_fps: f64) -> Result<String, String> {
+    if old_fps <= 0.0 {
+        return Err("old_fps must be greater than zero".to_string());
+    }
+    let mut camera_data: CameraData = serde_json::from_str(&data)
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    for (_, keyframe) in camera_data.iter_mut() {
+        let seconds = keyframe.frame as f64 / old_fps;
+        keyframe.frame = (seconds * new_fps).round() as i32;
+    }
+
+    serde_json::to_string_pretty(&camera_data)
+        .map_err(|e| format!("Failed to serialize: {}", e))
+}
+
+#[tauri::command]
+fn smooth_path(data: String, window_size: usize, smooth_rotation: bool) -> Result<String, String> {
     let camera_data: CameraData = serde_json::from_str(&data)
         .map_err(|e| format!("Failed to parse JSON: {}", e))?;
-    
+
     // Sort keyframes by timestamp
     let mut keyframes: Vec<(String, CameraKeyframe)> = camera_data.into_iter().collect();
     keyframes.sort_by(|a, b| a.1.timestamp.partial_cmp(&b.1.timestamp).unwrap());
-    
+
     let mut smoothed: CameraData = HashMap::new();
-    
+
     for (i, (key, keyframe)) in keyframes.iter().enumerate() {
         let start = i.saturating_sub(window_size / 2);
         let end = (i + window_size / 2 + 1).min(keyframes.len());
-        
+
         let mut avg_pos = Position { x: 0.0, y: 0.0, z: 0.0 };
         let mut avg_fov = 0.0;
         let count = (end - start) as f64;
-        
+
         for j in start..end {
             avg_pos.x += keyframes[j].1.position.x;
             avg_pos.y += keyframes[j].1.position.y;
             avg_pos.z += keyframes[j].1.position.z;
             avg_fov += keyframes[j].1.fov;
         }
-        
+
         let mut smoothed_kf = keyframe.clone();
         smoothed_kf.position = Position {
             x: avg_pos.x / count,
@@ -344,36 +451,327 @@ fn smooth_path(data: String, window_size: usize) -> Result<String, String> {
             z: avg_pos.z / count,
         };
         smoothed_kf.fov = avg_fov / count;
-        
+
+        if smooth_rotation {
+            let window_quats: Vec<UnitQuaternion<f64>> = (start..end)
+                .map(|j| rotation_to_quat(&keyframes[j].1.rotation))
+                .collect();
+            smoothed_kf.rotation = quat_to_rotation(&average_quaternions(&window_quats));
+        }
+
         smoothed.insert(key.clone(), smoothed_kf);
     }
-    
+
     serde_json::to_string_pretty(&smoothed)
         .map_err(|e| format!("Failed to serialize: {}", e))
 }
 
+fn catmull_rom(p0: f64, p1: f64, p2: f64, p3: f64, u: f64) -> f64 {
+    let u2 = u * u;
+    let u3 = u2 * u;
+
+    0.5 * ((2.0 * p1)
+        + (-p0 + p2) * u
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * u2
+        + (-p0 + 3.0 * p1 - 3.0 * p2 + p3) * u3)
+}
+
+#[tauri::command]
+fn resample_path(data: String, target_fps: f64) -> Result<String, String> {
+    if target_fps <= 0.0 {
+        return Err("target_fps must be greater than zero".to_string());
+    }
+    let camera_data: CameraData = serde_json::from_str(&data)
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    let mut keyframes: Vec<CameraKeyframe> = camera_data.into_values().collect();
+    keyframes.sort_by(|a, b| a.timestamp.partial_cmp(&b.timestamp).unwrap());
+
+    if keyframes.len() < 2 {
+        let passthrough: CameraData = keyframes
+            .into_iter()
+            .enumerate()
+            .map(|(i, kf)| (i.to_string(), kf))
+            .collect();
+        return serde_json::to_string_pretty(&passthrough)
+            .map_err(|e| format!("Failed to serialize: {}", e));
+    }
+
+    let has_resamplable_segment = keyframes
+        .windows(2)
+        .any(|w| w[1].timestamp - w[0].timestamp > f64::EPSILON);
+    if !has_resamplable_segment {
+        // Every consecutive pair shares the same timestamp, so there is no
+        // interval to spline across; pass the keyframes through unchanged
+        // (re-keyed by sequential index) rather than silently emitting an
+        // empty map.
+        let passthrough: CameraData = keyframes
+            .into_iter()
+            .enumerate()
+            .map(|(i, kf)| (i.to_string(), kf))
+            .collect();
+        return serde_json::to_string_pretty(&passthrough)
+            .map_err(|e| format!("Failed to serialize: {}", e));
+    }
+
+    let start_time = keyframes.first().unwrap().timestamp;
+    let end_time = keyframes.last().unwrap().timestamp;
+    let step = 1.0 / target_fps;
+    let num_steps = ((end_time - start_time) / step).floor() as i64;
+
+    let mut resampled: Vec<CameraKeyframe> = Vec::new();
+
+    for k in 0..=num_steps {
+        let t = start_time + k as f64 * step;
+
+        // Find the segment [P_i, P_{i+1}] containing t, skipping degenerate
+        // (zero-length) segments caused by duplicate timestamps.
+        let segment = (0..keyframes.len() - 1).find(|&i| {
+            let t0 = keyframes[i].timestamp;
+            let t1 = keyframes[i + 1].timestamp;
+            t1 - t0 > f64::EPSILON && t >= t0 - f64::EPSILON && t <= t1 + f64::EPSILON
+        });
+
+        let Some(i) = segment else {
+            continue;
+        };
+
+        let t0 = keyframes[i].timestamp;
+        let t1 = keyframes[i + 1].timestamp;
+        let u = ((t - t0) / (t1 - t0)).clamp(0.0, 1.0);
+
+        let p0 = if i == 0 { &keyframes[0] } else { &keyframes[i - 1] };
+        let p1 = &keyframes[i];
+        let p2 = &keyframes[i + 1];
+        let p3 = if i + 2 < keyframes.len() {
+            &keyframes[i + 2]
+        } else {
+            &keyframes[keyframes.len() - 1]
+        };
+
+        let timestamp = t;
+        let position = Position {
+            x: catmull_rom(p0.position.x, p1.position.x, p2.position.x, p3.position.x, u),
+            y: catmull_rom(p0.position.y, p1.position.y, p2.position.y, p3.position.y, u),
+            z: catmull_rom(p0.position.z, p1.position.z, p2.position.z, p3.position.z, u),
+        };
+        let fov = catmull_rom(p0.fov, p1.fov, p2.fov, p3.fov, u);
+
+        resampled.push(CameraKeyframe {
+            fov,
+            frame: (timestamp * target_fps).round() as i32,
+            position,
+            rotation: p1.rotation.clone(),
+            timestamp,
+            weight: p1.weight,
+        });
+    }
+
+    let resampled_map: CameraData = resampled
+        .into_iter()
+        .enumerate()
+        .map(|(i, kf)| (i.to_string(), kf))
+        .collect();
+
+    serde_json::to_string_pretty(&resampled_map)
+        .map_err(|e| format!("Failed to serialize: {}", e))
+}
+
+// ========================================
+// PATH GENERATORS
+// ========================================
+
+#[tauri::command]
+fn generate_orbit(
+    center_x: f64,
+    center_y: f64,
+    center_z: f64,
+    radius: f64,
+    height: f64,
+    num_frames: u32,
+    duration: f64,
+    fov: f64,
+    fps: Option<f64>,
+) -> Result<String, String> {
+    if num_frames == 0 {
+        return Err("num_frames must be greater than zero".to_string());
+    }
+    let fps = fps.unwrap_or(DEFAULT_FPS);
+
+    let mut camera_data: CameraData = HashMap::new();
+
+    for k in 0..num_frames {
+        let theta = 2.0 * std::f64::consts::PI * (k as f64) / (num_frames as f64);
+
+        let position = Position {
+            x: center_x + radius * theta.cos(),
+            y: center_y + radius * theta.sin(),
+            z: center_z + height,
+        };
+
+        // Aim the camera at the orbit center
+        let dir_x = center_x - position.x;
+        let dir_y = center_y - position.y;
+        let dir_z = center_z - position.z;
+        let horizontal_len = (dir_x * dir_x + dir_y * dir_y).sqrt();
+
+        let yaw = dir_y.atan2(dir_x);
+        let pitch = dir_z.atan2(horizontal_len);
+
+        let rotation = Rotation {
+            pitch: rad_to_uu(pitch),
+            roll: 0,
+            yaw: rad_to_uu(yaw),
+        };
+
+        let timestamp = duration * (k as f64) / (num_frames as f64);
+
+        camera_data.insert(
+            k.to_string(),
+            CameraKeyframe {
+                fov,
+                frame: (timestamp * fps).round() as i32,
+                position,
+                rotation,
+                timestamp,
+                weight: 1.0,
+            },
+        );
+    }
+
+    serde_json::to_string_pretty(&camera_data)
+        .map_err(|e| format!("Failed to serialize: {}", e))
+}
+
+// ========================================
+// INTERCHANGE
+// ========================================
+
+// Distance along a keyframe's forward vector used to synthesize a look-at
+// target for tools that expect the position+target event form.
+const EXPORT_TARGET_DISTANCE: f64 = 500.0;
+
+#[tauri::command]
+fn import_camera_events(json: String, fps: Option<f64>) -> Result<String, String> {
+    let events: Vec<CameraEvent> = serde_json::from_str(&json)
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+    let fps = fps.unwrap_or(DEFAULT_FPS);
+
+    let mut camera_data: CameraData = HashMap::new();
+
+    for (i, event) in events.into_iter().enumerate() {
+        let dir_x = event.target.x - event.position.x;
+        let dir_y = event.target.y - event.position.y;
+        let dir_z = event.target.z - event.position.z;
+        let horizontal_len = (dir_x * dir_x + dir_y * dir_y).sqrt();
+
+        let yaw = dir_y.atan2(dir_x);
+        let pitch = dir_z.atan2(horizontal_len);
+
+        let keyframe = CameraKeyframe {
+            fov: event.fov,
+            frame: (event.time * fps).round() as i32,
+            position: Position {
+                x: event.position.x,
+                y: event.position.y,
+                z: event.position.z,
+            },
+            rotation: Rotation {
+                pitch: rad_to_uu(pitch),
+                roll: 0,
+                yaw: rad_to_uu(yaw),
+            },
+            timestamp: event.time,
+            weight: 1.0,
+        };
+
+        camera_data.insert(i.to_string(), keyframe);
+    }
+
+    serde_json::to_string_pretty(&camera_data)
+        .map_err(|e| format!("Failed to serialize: {}", e))
+}
+
+#[tauri::command]
+fn export_camera_events(data: String) -> Result<String, String> {
+    let camera_data: CameraData = serde_json::from_str(&data)
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    let mut keyframes: Vec<CameraKeyframe> = camera_data.into_values().collect();
+    keyframes.sort_by(|a, b| a.timestamp.partial_cmp(&b.timestamp).unwrap());
+
+    let events: Vec<CameraEvent> = keyframes
+        .into_iter()
+        .map(|kf| {
+            let forward = rotation_to_quat(&kf.rotation) * Vector3::x();
+            let target = Vec3Event {
+                x: kf.position.x + forward.x * EXPORT_TARGET_DISTANCE,
+                y: kf.position.y + forward.y * EXPORT_TARGET_DISTANCE,
+                z: kf.position.z + forward.z * EXPORT_TARGET_DISTANCE,
+            };
+
+            CameraEvent {
+                position: Vec3Event {
+                    x: kf.position.x,
+                    y: kf.position.y,
+                    z: kf.position.z,
+                },
+                target,
+                fov: kf.fov,
+                time: kf.timestamp,
+            }
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&events)
+        .map_err(|e| format!("Failed to serialize: {}", e))
+}
+
 // ========================================
 // UTILITY FUNCTIONS
 // ========================================
 
 #[tauri::command]
-fn get_path_stats(data: String) -> Result<String, String> {
+fn get_path_stats(data: String, fps: Option<f64>) -> Result<String, String> {
     let camera_data: CameraData = serde_json::from_str(&data)
         .map_err(|e| format!("Failed to parse JSON: {}", e))?;
-    
+    let declared_fps = fps.unwrap_or(DEFAULT_FPS);
+
     let mut timestamps: Vec<f64> = camera_data.values().map(|kf| kf.timestamp).collect();
     timestamps.sort_by(|a, b| a.partial_cmp(b).unwrap());
-    
+
     let duration = timestamps.last().copied().unwrap_or(0.0) - timestamps.first().copied().unwrap_or(0.0);
     let keyframe_count = camera_data.len();
-    
+
+    let avg_spacing = if keyframe_count > 1 {
+        duration / (keyframe_count - 1) as f64
+    } else {
+        0.0
+    };
+
+    // Infer the fps the path was actually recorded at from each keyframe's
+    // Frame/Timestamp ratio, so mismatches with the declared fps are visible.
+    let inferred_samples: Vec<f64> = camera_data
+        .values()
+        .filter(|kf| kf.timestamp.abs() > f64::EPSILON)
+        .map(|kf| kf.frame as f64 / kf.timestamp)
+        .collect();
+    let inferred_fps = if inferred_samples.is_empty() {
+        declared_fps
+    } else {
+        inferred_samples.iter().sum::<f64>() / inferred_samples.len() as f64
+    };
+
     let stats = serde_json::json!({
         "keyframes": keyframe_count,
         "duration": duration,
         "min_time": timestamps.first().copied().unwrap_or(0.0),
-        "max_time": timestamps.last().copied().unwrap_or(0.0)
+        "max_time": timestamps.last().copied().unwrap_or(0.0),
+        "declared_fps": declared_fps,
+        "inferred_fps": inferred_fps,
+        "avg_keyframe_spacing": avg_spacing
     });
-    
+
     Ok(stats.to_string())
 }
 
@@ -435,11 +833,150 @@ fn main() {
             transform_time_offset,
             // Path operations
             reverse_path,
+            reframe_path,
             smooth_path,
+            resample_path,
+            // Generators
+            generate_orbit,
+            // Interchange
+            import_camera_events,
+            export_camera_events,
             // Utilities
             get_path_stats,
             open_url
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catmull_rom_passes_through_the_inner_control_points() {
+        assert_eq!(catmull_rom(0.0, 1.0, 2.0, 3.0, 0.0), 1.0);
+        assert_eq!(catmull_rom(0.0, 1.0, 2.0, 3.0, 1.0), 2.0);
+    }
+
+    #[test]
+    fn catmull_rom_matches_hand_computed_midpoint() {
+        // p0=0, p1=0, p2=1, p3=1, u=0.5 -> 0.5 by direct substitution into
+        // the uniform Catmull-Rom basis.
+        assert!((catmull_rom(0.0, 0.0, 1.0, 1.0, 0.5) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn resample_path_passes_through_all_duplicate_timestamps() {
+        let data = serde_json::json!({
+            "0": {
+                "FOV": 90.0, "Frame": 0,
+                "Position": {"X": 0.0, "Y": 0.0, "Z": 0.0},
+                "Rotation": {"Pitch": 0, "Roll": 0, "Yaw": 0},
+                "Timestamp": 1.0, "Weight": 1.0
+            },
+            "1": {
+                "FOV": 90.0, "Frame": 0,
+                "Position": {"X": 1.0, "Y": 1.0, "Z": 1.0},
+                "Rotation": {"Pitch": 0, "Roll": 0, "Yaw": 0},
+                "Timestamp": 1.0, "Weight": 1.0
+            }
+        })
+        .to_string();
+
+        let result = resample_path(data, 30.0).unwrap();
+        let resampled: CameraData = serde_json::from_str(&result).unwrap();
+        assert_eq!(resampled.len(), 2);
+    }
+
+    #[test]
+    fn average_quaternions_of_identical_rotations_is_unchanged() {
+        let identity = UnitQuaternion::identity();
+        let avg = average_quaternions(&[identity, identity, identity]);
+        assert!((avg.angle_to(&identity)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn average_quaternions_flips_opposite_hemisphere_samples() {
+        let identity = UnitQuaternion::identity();
+        // -q represents the same rotation as q, but would cancel it out in a
+        // naive sum instead of reinforcing it.
+        let negated = UnitQuaternion::from_quaternion(-identity.quaternion());
+        let avg = average_quaternions(&[identity, negated]);
+        assert!(avg.angle_to(&identity).abs() < 1e-9);
+    }
+
+    #[test]
+    fn reflect_rotation_of_identity_across_y_is_unchanged() {
+        // Forward (+X) and up (+Z) both have zero Y component, so reflecting
+        // across the Y axis plane should reconstruct the identity rotation.
+        let identity = Rotation { pitch: 0, yaw: 0, roll: 0 };
+        let reflected = reflect_rotation(&identity, "y");
+        assert_eq!(reflected.pitch, 0);
+        assert_eq!(reflected.yaw, 0);
+        assert_eq!(reflected.roll, 0);
+    }
+
+    #[test]
+    fn generate_orbit_frame_zero_looks_at_the_center() {
+        let result = generate_orbit(0.0, 0.0, 0.0, 100.0, 0.0, 4, 4.0, 90.0, None).unwrap();
+        let data: CameraData = serde_json::from_str(&result).unwrap();
+        let kf = &data["0"];
+
+        let forward = rotation_to_quat(&kf.rotation) * Vector3::x();
+        let to_center =
+            Vector3::new(-kf.position.x, -kf.position.y, -kf.position.z).normalize();
+
+        assert!((forward - to_center).norm() < 1e-3);
+    }
+
+    #[test]
+    fn import_then_export_preserves_look_at_direction() {
+        let events = serde_json::json!([
+            {
+                "position": {"x": 0.0, "y": 0.0, "z": 0.0},
+                "target": {"x": 100.0, "y": 50.0, "z": 25.0},
+                "fov": 90.0,
+                "time": 0.0
+            }
+        ])
+        .to_string();
+
+        let imported = import_camera_events(events, None).unwrap();
+        let exported = export_camera_events(imported).unwrap();
+        let round_tripped: Vec<CameraEvent> = serde_json::from_str(&exported).unwrap();
+        let event = &round_tripped[0];
+
+        let original_dir =
+            Vector3::new(100.0 - 0.0, 50.0 - 0.0, 25.0 - 0.0).normalize();
+        let round_tripped_dir = Vector3::new(
+            event.target.x - event.position.x,
+            event.target.y - event.position.y,
+            event.target.z - event.position.z,
+        )
+        .normalize();
+
+        assert!((original_dir - round_tripped_dir).norm() < 1e-3);
+    }
+
+    #[test]
+    fn reframe_path_round_trips_frame_numbers_within_rounding() {
+        let data = serde_json::json!({
+            "0": {
+                "FOV": 90.0, "Frame": 30,
+                "Position": {"X": 0.0, "Y": 0.0, "Z": 0.0},
+                "Rotation": {"Pitch": 0, "Roll": 0, "Yaw": 0},
+                "Timestamp": 1.0, "Weight": 1.0
+            }
+        })
+        .to_string();
+
+        let reframed = reframe_path(data, 30.0, 60.0).unwrap();
+        let sped_up: CameraData = serde_json::from_str(&reframed).unwrap();
+        assert_eq!(sped_up["0"].frame, 60);
+
+        let restored = reframe_path(reframed, 60.0, 30.0).unwrap();
+        let back: CameraData = serde_json::from_str(&restored).unwrap();
+        assert_eq!(back["0"].frame, 30);
+    }
 }
\ No newline at end of file